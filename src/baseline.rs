@@ -0,0 +1,53 @@
+use color_eyre::{eyre::Context, Result};
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+
+/// A baseline snapshot of a project's discovered message keys, persisted as a small JSON
+/// sidecar so a later run can diff against it and flag keys added or removed since it was
+/// written. This catches drift that `check_key_length`/`check_files_equal` can't: a key
+/// deleted from every locale file at once still leaves all files agreeing with each other.
+#[derive(Debug, Serialize, Deserialize)]
+struct Snapshot {
+    keys: BTreeSet<String>,
+}
+
+/// Writes a baseline snapshot of `keys` to `path` as JSON.
+pub fn write_snapshot(path: &str, keys: BTreeSet<String>) -> Result<()> {
+    let json = serde_json::to_string_pretty(&Snapshot { keys })
+        .wrap_err("could not serialize baseline snapshot")?;
+
+    fs::write(path, json)
+        .wrap_err_with(|| format!("could not write baseline snapshot to `{path}`"))
+}
+
+/// Keys added or removed relative to a baseline snapshot.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Drift {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl Drift {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Reads the baseline snapshot at `path` and diffs `current` against it.
+pub fn diff_against_snapshot(path: &str, current: &BTreeSet<String>) -> Result<Drift> {
+    let contents = fs::read_to_string(path)
+        .wrap_err_with(|| format!("could not read baseline snapshot `{path}`"))?;
+    let snapshot: Snapshot = serde_json::from_str(&contents)
+        .wrap_err_with(|| format!("could not parse baseline snapshot `{path}`"))?;
+
+    let added = current.difference(&snapshot.keys).cloned().collect();
+    let removed = snapshot.keys.difference(current).cloned().collect();
+
+    Ok(Drift { added, removed })
+}
+
+#[cfg(test)]
+#[path = "tests/baseline_tests.rs"]
+mod baseline_tests;
@@ -0,0 +1,163 @@
+use color_eyre::{eyre::Context, Result};
+
+use glob::Pattern;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Resolves `--file` entries (explicit file paths, directories, or glob patterns such as
+/// `lib/l10n` or `**/*.arb`) into concrete file paths, skipping anything that matches an
+/// `--ignore` pattern.
+///
+/// Each entry is resolved by walking the filesystem tree rather than expanding every
+/// possible match up front: a glob is split into a literal base directory plus the
+/// sub-pattern still to match, so unrelated subtrees are never visited, and ignore
+/// patterns are checked before descending into a directory so an ignored directory is
+/// never recursed into.
+///
+/// A directory entry (as opposed to a file or a glob pattern) is walked recursively,
+/// collecting every `.arb`/`.json` file beneath it and skipping everything else, so
+/// pointing `--file` at a single locale directory such as `lib/l10n/` is equivalent to
+/// listing each of its files individually.
+///
+/// # Arguments
+///
+/// * `includes` - The raw `--file` entries: file paths, directories, or glob patterns.
+/// * `ignores` - Glob patterns matched against each candidate's path relative to the
+///   directory it was discovered under; a match excludes the candidate.
+///
+/// # Errors
+///
+/// This function will return an error if an `--ignore` pattern is not a valid glob, if an
+/// include entry is a glob pattern whose base directory cannot be walked, or if reading a
+/// directory fails (e.g. due to file permissions).
+pub fn resolve_files(includes: &[String], ignores: &[String]) -> Result<Vec<String>> {
+    let ignore_patterns = compile_patterns(ignores)?;
+
+    let mut resolved = Vec::new();
+    for include in includes {
+        resolve_include(include, &ignore_patterns, &mut resolved)?;
+    }
+
+    resolved.sort();
+    resolved.dedup();
+
+    Ok(resolved)
+}
+
+fn compile_patterns(patterns: &[String]) -> Result<Vec<Pattern>> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            Pattern::new(pattern).wrap_err_with(|| format!("invalid glob pattern `{pattern}`"))
+        })
+        .collect()
+}
+
+fn resolve_include(include: &str, ignores: &[Pattern], out: &mut Vec<String>) -> Result<()> {
+    let path = Path::new(include);
+
+    if path.is_file() {
+        out.push(include.to_string());
+        return Ok(());
+    }
+
+    if path.is_dir() {
+        return walk(path, path, None, ignores, out);
+    }
+
+    let (base, sub_pattern) = split_glob(include);
+    let pattern = Pattern::new(&sub_pattern)
+        .wrap_err_with(|| format!("invalid glob pattern `{include}`"))?;
+
+    walk(&base, &base, Some(&pattern), ignores, out)
+}
+
+/// Splits a glob pattern into the literal base directory to start walking from and the
+/// sub-pattern that must match each candidate's path relative to that base, e.g.
+/// `lib/l10n/**/*.arb` splits into (`lib/l10n`, `**/*.arb`).
+fn split_glob(pattern: &str) -> (PathBuf, String) {
+    let mut components: Vec<&str> = pattern.split('/').collect();
+    let mut base_components = Vec::new();
+
+    while let Some(first) = components.first() {
+        if has_glob_chars(first) {
+            break;
+        }
+        base_components.push(*first);
+        components.remove(0);
+    }
+
+    let base = if base_components.is_empty() {
+        PathBuf::from(".")
+    } else {
+        PathBuf::from(base_components.join("/"))
+    };
+
+    let sub_pattern = if components.is_empty() {
+        "*".to_string()
+    } else {
+        components.join("/")
+    };
+
+    (base, sub_pattern)
+}
+
+fn has_glob_chars(segment: &str) -> bool {
+    segment.contains(['*', '?', '[', ']'])
+}
+
+/// Recursively walks `dir`, collecting files that match `pattern` (relative to `base`) or,
+/// when `pattern` is `None`, every `.arb`/`.json` file, while pruning any entry whose path
+/// relative to `base` matches an ignore pattern.
+fn walk(
+    base: &Path,
+    dir: &Path,
+    pattern: Option<&Pattern>,
+    ignores: &[Pattern],
+    out: &mut Vec<String>,
+) -> Result<()> {
+    let entries =
+        fs::read_dir(dir).wrap_err_with(|| format!("could not read directory `{}`", dir.display()))?;
+
+    for entry in entries {
+        let entry = entry.wrap_err("could not read directory entry")?;
+        let path = entry.path();
+        let relative = relative_path(base, &path);
+
+        if ignores.iter().any(|ignore| ignore.matches(&relative)) {
+            continue;
+        }
+
+        if path.is_dir() {
+            walk(base, &path, pattern, ignores, out)?;
+            continue;
+        }
+
+        let is_match = match pattern {
+            Some(pattern) => pattern.matches(&relative),
+            None => has_arb_or_json_extension(&path),
+        };
+
+        if is_match {
+            out.push(path.to_string_lossy().into_owned());
+        }
+    }
+
+    Ok(())
+}
+
+fn relative_path(base: &Path, path: &Path) -> String {
+    path.strip_prefix(base)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn has_arb_or_json_extension(path: &Path) -> bool {
+    let file_name = path.to_string_lossy();
+    file_name.ends_with(".arb") || file_name.ends_with(".json")
+}
+
+#[cfg(test)]
+#[path = "tests/discover_tests.rs"]
+mod discover_tests;
@@ -1,11 +1,77 @@
 use color_eyre::{eyre::Context, eyre::ContextCompat, Result};
 
-use std::collections::HashMap;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::BufReader;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-pub type Data = HashMap<String, String>;
+/// A parsed ARB/JSON file, split into the three kinds of entries the ARB
+/// grammar mixes into a single JSON object: `@@`-prefixed file-level
+/// attributes (e.g. `"@@locale"`), `@`-prefixed resource attributes that
+/// describe a sibling message (e.g. `"@greeting"`), and the translatable
+/// messages themselves. Keeping these apart means the checks below only
+/// ever compare the keys that are meant to be translated.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Data {
+    /// File-level attributes, keyed without their `@@` prefix (e.g. `locale`).
+    pub attributes: HashMap<String, Value>,
+    /// Translatable messages, keyed by message name.
+    pub messages: HashMap<String, Value>,
+    /// Resource attributes, keyed by the message name they describe (without
+    /// the leading `@`).
+    pub resource_attributes: HashMap<String, Value>,
+}
+
+impl Data {
+    /// Builds a `Data` whose only content is the given translatable
+    /// messages, with no file-level or resource attributes.
+    ///
+    /// This is mostly useful in tests, where a plain key/value message map
+    /// is easier to write than the full ARB shape.
+    pub fn from_messages<I, K, V>(messages: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        Data {
+            messages: messages
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+            ..Data::default()
+        }
+    }
+}
+
+/// Small navigation helpers over `serde_json::Value`, in the spirit of
+/// `Value::as_str`/`as_bool`/etc. but keyed by object field name, so callers
+/// don't have to chain `.get(key).and_then(Value::as_str)` by hand.
+pub trait ValueExt {
+    fn get_str(&self, key: &str) -> Option<&str>;
+    fn get_bool(&self, key: &str) -> Option<bool>;
+    fn get_object(&self, key: &str) -> Option<&Map<String, Value>>;
+    fn get_array(&self, key: &str) -> Option<&Vec<Value>>;
+}
+
+impl ValueExt for Value {
+    fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key)?.as_str()
+    }
+
+    fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key)?.as_bool()
+    }
+
+    fn get_object(&self, key: &str) -> Option<&Map<String, Value>> {
+        self.get(key)?.as_object()
+    }
+
+    fn get_array(&self, key: &str) -> Option<&Vec<Value>> {
+        self.get(key)?.as_array()
+    }
+}
 
 /// Checks the file extensions of a given list of file paths.
 ///
@@ -52,11 +118,11 @@ pub fn check_file_extension(file_paths: &Vec<String>) -> Result<()> {
     Ok(())
 }
 
-/// Check if all `Data` objects in the given vector have the same keys.
+/// Check if all `Data` objects in the given vector have the same translatable message keys.
 ///
-/// This function takes a vector of `Data` objects and ensures that all objects have the same
-/// set of keys. It returns a `Result` that is `Ok(())` if the keys are the same, or an error
-/// if the keys differ.
+/// This function takes a vector of `Data` objects and ensures that all of them have the same
+/// set of message keys, ignoring `@@` file-level attributes and `@` resource attributes. It
+/// returns a `Result` that is `Ok(())` if the keys are the same, or an error if they differ.
 ///
 /// # Arguments
 ///
@@ -66,16 +132,15 @@ pub fn check_file_extension(file_paths: &Vec<String>) -> Result<()> {
 ///
 /// This function will return an error if:
 ///
-/// * The keys are not the same for all `Data` objects in the vector.
+/// * The message keys are not the same for all `Data` objects in the vector.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use std::collections::HashMap;
 /// use my_module::{check_files_equal, Data};
 ///
-/// let data1: Data = [("key1", "value1"), ("key2", "value2")].iter().cloned().collect();
-/// let data2: Data = [("key1", "value1"), ("key2", "value2")].iter().cloned().collect();
+/// let data1 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
+/// let data2 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
 /// let data_vec: Vec<Data> = vec![data1, data2];
 ///
 /// match check_files_equal(data_vec) {
@@ -91,7 +156,7 @@ pub fn check_files_equal(data: Vec<Data>) -> Result<()> {
     let mut key_iter = data
         .iter()
         .map(|d| {
-            let mut keys = d.keys().collect::<Vec<_>>();
+            let mut keys = d.messages.keys().collect::<Vec<_>>();
             keys.sort();
 
             keys
@@ -140,6 +205,212 @@ pub fn check_files_equal(data: Vec<Data>) -> Result<()> {
 /// let invalid_path = Path::new("non_existent_file.txt");
 /// assert!(check_files_exist(invalid_path).is_err());
 /// ```
+/// Check that every ICU placeholder used in a shared message key stays the same across files.
+///
+/// ARB messages embed ICU placeholders like `{name}` and plural/select blocks like
+/// `{count, plural, one{1 item} other{{count} items}}`. A translation that drops or
+/// misspells a placeholder silently breaks formatting at runtime, so this walks each
+/// message string (recursing into `plural`/`select` sub-messages) to collect the set of
+/// placeholder names it references, then compares that set for every key shared with the
+/// first file.
+///
+/// # Arguments
+///
+/// * `data` - A slice of `Data` objects whose messages should reference the same placeholders.
+///
+/// # Errors
+///
+/// This function will return an error listing every message key whose placeholder set
+/// differs from the first file's.
+///
+/// # Examples
+///
+/// ```rust
+/// use my_module::{check_placeholders_equal, Data};
+///
+/// let data1 = Data::from_messages([("greeting", "Hello {name}")]);
+/// let data2 = Data::from_messages([("greeting", "Bonjour {name}")]);
+/// assert!(check_placeholders_equal(&[data1, data2]).is_ok());
+///
+/// let data3 = Data::from_messages([("greeting", "Hello {name}")]);
+/// let data4 = Data::from_messages([("greeting", "Bonjour")]);
+/// assert!(check_placeholders_equal(&[data3, data4]).is_err());
+/// ```
+pub fn check_placeholders_equal(data: &[Data]) -> Result<()> {
+    let mut file_iter = data.iter();
+    let first = file_iter.next().wrap_err("could not get first item")?;
+    let first_placeholders = message_placeholders(first);
+
+    let mut mismatches = Vec::new();
+    for other in file_iter {
+        let other_placeholders = message_placeholders(other);
+
+        for (key, placeholders) in &first_placeholders {
+            let Some(other_set) = other_placeholders.get(key) else {
+                continue;
+            };
+
+            if placeholders != other_set {
+                mismatches.push(format!(
+                    "`{key}`: {:?} vs {:?}",
+                    sorted_placeholders(placeholders),
+                    sorted_placeholders(other_set)
+                ));
+            }
+        }
+    }
+
+    if !mismatches.is_empty() {
+        return Err(color_eyre::eyre::eyre!(
+            "placeholders differ for keys: {}",
+            mismatches.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+/// Builds a map of message key to the set of ICU placeholder names it references.
+fn message_placeholders(data: &Data) -> HashMap<String, HashSet<String>> {
+    data.messages
+        .iter()
+        .filter_map(|(key, value)| {
+            let message = value.as_str()?;
+            let mut placeholders = HashSet::new();
+            collect_placeholders(message, &mut placeholders);
+            Some((key.clone(), placeholders))
+        })
+        .collect()
+}
+
+/// Scans an ICU message string for placeholder names, recursing into `plural`/`select`
+/// sub-messages but treating their category keywords (`one`, `other`, `=0`, ...) as
+/// selectors rather than placeholders.
+fn collect_placeholders(message: &str, placeholders: &mut HashSet<String>) {
+    let chars: Vec<char> = message.chars().collect();
+    let mut index = 0;
+    while index < chars.len() {
+        if chars[index] == '{' {
+            index = collect_placeholder(&chars, index + 1, placeholders);
+        } else {
+            index += 1;
+        }
+    }
+}
+
+/// Parses one `{...}` placeholder, starting just after its opening brace, and returns the
+/// index just past its matching closing brace.
+fn collect_placeholder(chars: &[char], start: usize, placeholders: &mut HashSet<String>) -> usize {
+    let (name, mut index) = read_identifier(chars, start);
+    if !name.is_empty() {
+        placeholders.insert(name);
+    }
+
+    index = skip_whitespace(chars, index);
+
+    if index >= chars.len() {
+        return index;
+    }
+
+    if chars[index] == '}' {
+        return index + 1;
+    }
+
+    if chars[index] != ',' {
+        return index;
+    }
+
+    let (kind, after_kind) = read_identifier(chars, skip_whitespace(chars, index + 1));
+    index = skip_whitespace(chars, after_kind);
+
+    if matches!(kind.as_str(), "plural" | "select" | "selectordinal") && chars.get(index) == Some(&',')
+    {
+        return collect_plural_arms(chars, index + 1, placeholders);
+    }
+
+    // Some other argument type (e.g. `number`, `date`): skip to its matching closing brace
+    // without treating anything inside as a placeholder.
+    skip_to_matching_brace(chars, index)
+}
+
+/// Parses the `selector{submessage}` arms of a `plural`/`select` block, starting just after
+/// its type's trailing comma, and returns the index just past the block's closing brace.
+fn collect_plural_arms(chars: &[char], start: usize, placeholders: &mut HashSet<String>) -> usize {
+    let mut index = start;
+    loop {
+        index = skip_whitespace(chars, index);
+
+        match chars.get(index) {
+            None => return index,
+            Some('}') => return index + 1,
+            _ => {}
+        }
+
+        // Selector keyword, e.g. `one`, `other`, or an exact match like `=0`.
+        while index < chars.len() && chars[index] != '{' && !chars[index].is_whitespace() {
+            index += 1;
+        }
+        index = skip_whitespace(chars, index);
+
+        if chars.get(index) == Some(&'{') {
+            index = collect_submessage(chars, index + 1, placeholders);
+        }
+    }
+}
+
+/// Parses a plural/select arm's submessage, starting just after its opening brace, and
+/// returns the index just past its matching closing brace.
+fn collect_submessage(chars: &[char], start: usize, placeholders: &mut HashSet<String>) -> usize {
+    let mut index = start;
+    while index < chars.len() {
+        match chars[index] {
+            '}' => return index + 1,
+            '{' => index = collect_placeholder(chars, index + 1, placeholders),
+            _ => index += 1,
+        }
+    }
+    index
+}
+
+fn skip_to_matching_brace(chars: &[char], start: usize) -> usize {
+    let mut depth = 1;
+    let mut index = start;
+    while index < chars.len() && depth > 0 {
+        match chars[index] {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => {}
+        }
+        index += 1;
+    }
+    index
+}
+
+fn skip_whitespace(chars: &[char], start: usize) -> usize {
+    let mut index = start;
+    while index < chars.len() && chars[index].is_whitespace() {
+        index += 1;
+    }
+    index
+}
+
+/// Reads an `[A-Za-z0-9_]` identifier starting at `start` (skipping leading whitespace) and
+/// returns it along with the index just past it.
+fn read_identifier(chars: &[char], start: usize) -> (String, usize) {
+    let start = skip_whitespace(chars, start);
+    let mut index = start;
+    while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+        index += 1;
+    }
+    (chars[start..index].iter().collect(), index)
+}
+
+fn sorted_placeholders(placeholders: &HashSet<String>) -> Vec<&String> {
+    let mut names: Vec<&String> = placeholders.iter().collect();
+    names.sort();
+    names
+}
+
 pub fn check_files_exist<P: AsRef<Path>>(file_path: P) -> Result<()> {
     let is_exist = file_path
         .as_ref()
@@ -159,11 +430,12 @@ pub fn check_files_exist<P: AsRef<Path>>(file_path: P) -> Result<()> {
     Ok(())
 }
 
-/// Check if all `Data` objects in the given slice have the same key-value pair count.
+/// Check if all `Data` objects in the given slice have the same number of translatable messages.
 ///
-/// This function takes a slice of `Data` objects and ensures that all objects have the same
-/// number of key-value pairs. It returns a `Result` that is `Ok(())` if the key-value pair
-/// count is the same, or an error if the count differs or any `Data` object is empty.
+/// This function takes a slice of `Data` objects and ensures that all of them have the same
+/// number of message keys, ignoring `@@` file-level attributes and `@` resource attributes. It
+/// returns a `Result` that is `Ok(())` if the message count is the same, or an error if the
+/// count differs or any `Data` object has no messages.
 ///
 /// # Arguments
 ///
@@ -173,17 +445,16 @@ pub fn check_files_exist<P: AsRef<Path>>(file_path: P) -> Result<()> {
 ///
 /// This function will return an error if:
 ///
-/// * Any `Data` object in the slice is empty.
-/// * The key-value pair count is not the same for all `Data` objects in the slice.
+/// * Any `Data` object in the slice has no messages.
+/// * The message count is not the same for all `Data` objects in the slice.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use std::collections::HashMap;
 /// use my_module::{check_key_length, Data};
 ///
-/// let data1: Data = [("key1", "value1"), ("key2", "value2")].iter().cloned().collect();
-/// let data2: Data = [("key1", "value1"), ("key2", "value2")].iter().cloned().collect();
+/// let data1 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
+/// let data2 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
 /// let data_slice: &[Data] = &[data1, data2];
 ///
 /// match check_key_length(data_slice) {
@@ -196,7 +467,10 @@ pub fn check_files_exist<P: AsRef<Path>>(file_path: P) -> Result<()> {
 /// }
 /// ```
 pub fn check_key_length(data: &[Data]) -> Result<()> {
-    let mut key_lengths = data.iter().map(|d| d.len()).collect::<Vec<usize>>();
+    let mut key_lengths = data
+        .iter()
+        .map(|d| d.messages.len())
+        .collect::<Vec<usize>>();
     key_lengths.sort();
 
     let first = key_lengths.first().wrap_err("no first item")?;
@@ -216,11 +490,12 @@ pub fn check_key_length(data: &[Data]) -> Result<()> {
     Ok(())
 }
 
-/// Read the JSON data from a file and deserialize it into a `Data` object.
+/// Read the JSON data from a file and split it into ARB attributes and messages.
 ///
-/// This function takes a file path as input and attempts to read the JSON
-/// contents of the file, returning a `Result` containing the deserialized `Data`
-/// object or an error if reading the file or parsing the JSON fails.
+/// This function takes a file path as input and attempts to read the JSON contents of the
+/// file, classifying each top-level key before returning the resulting `Data`: keys starting
+/// with `@@` become file-level attributes, keys starting with `@` become resource attributes
+/// attached to their sibling message, and every other key becomes a translatable message.
 ///
 /// # Arguments
 ///
@@ -231,7 +506,7 @@ pub fn check_key_length(data: &[Data]) -> Result<()> {
 /// This function will return an error if:
 ///
 /// * The file cannot be opened (e.g., due to permission issues or the file does not exist).
-/// * The contents of the file cannot be deserialized into a `Data` object (e.g., due to malformed JSON).
+/// * The contents of the file cannot be deserialized as a JSON object (e.g., due to malformed JSON).
 ///
 /// # Examples
 ///
@@ -239,11 +514,11 @@ pub fn check_key_length(data: &[Data]) -> Result<()> {
 /// use std::path::Path;
 /// use my_module::{read_json, Data};
 ///
-/// let data = read_json(Path::new("path/to/your/file.json"));
+/// let data = read_json(Path::new("path/to/your/file.arb"));
 ///
 /// match data {
 ///     Ok(parsed_data) => {
-///         println!("Data: {:?}", parsed_data);
+///         println!("Messages: {:?}", parsed_data.messages);
 ///     }
 ///     Err(e) => {
 ///         eprintln!("Error: {}", e);
@@ -254,9 +529,96 @@ pub fn read_json<P: AsRef<Path>>(file_path: P) -> Result<Data> {
     let file = File::open(file_path).wrap_err("could not open file")?;
     let reader = BufReader::new(file);
 
-    let json: Data = serde_json::from_reader(reader).wrap_err("could not read json or arb file")?;
+    let raw: HashMap<String, Value> =
+        serde_json::from_reader(reader).wrap_err("could not read json or arb file")?;
+
+    let mut data = Data::default();
+    for (key, value) in raw {
+        if let Some(name) = key.strip_prefix("@@") {
+            data.attributes.insert(name.to_string(), value);
+        } else if let Some(name) = key.strip_prefix('@') {
+            data.resource_attributes.insert(name.to_string(), value);
+        } else {
+            data.messages.insert(key, value);
+        }
+    }
+
+    Ok(data)
+}
+
+/// Reads a file's ARB/JSON data like `read_json`, but also resolves and merges any
+/// `@@include` glossary files before returning.
+///
+/// `@@include` lists other ARB/JSON files (paths resolved relative to the including file)
+/// whose messages, attributes, and resource attributes are merged in first, so the
+/// including file's own entries take precedence; `@@unset` then lists keys to drop from
+/// the merged messages, letting a file opt out of a specific inherited key.
+///
+/// # Errors
+///
+/// This function returns the same errors as `read_json`, plus an error if an `@@include`
+/// chain forms a cycle.
+pub fn read_json_with_includes<P: AsRef<Path>>(file_path: P) -> Result<Data> {
+    let mut visiting = HashSet::new();
+    read_json_with_includes_inner(file_path.as_ref(), &mut visiting)
+}
+
+fn read_json_with_includes_inner(file_path: &Path, visiting: &mut HashSet<PathBuf>) -> Result<Data> {
+    let canonical = file_path
+        .canonicalize()
+        .wrap_err_with(|| format!("could not resolve path `{}`", file_path.display()))?;
+
+    if !visiting.insert(canonical.clone()) {
+        return Err(color_eyre::eyre::eyre!(
+            "include cycle detected at `{}`",
+            file_path.display()
+        ));
+    }
+
+    let own = read_json(file_path)?;
+    let base_dir = file_path.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut merged = Data::default();
+    for include_path in include_paths(&own) {
+        let included = read_json_with_includes_inner(&base_dir.join(&include_path), visiting)?;
+        merged.messages.extend(included.messages);
+        merged.attributes.extend(included.attributes);
+        merged
+            .resource_attributes
+            .extend(included.resource_attributes);
+    }
+
+    visiting.remove(&canonical);
+
+    let unset_keys = unset_keys(&own);
+
+    merged.messages.extend(own.messages);
+    merged.attributes.extend(own.attributes);
+    merged.resource_attributes.extend(own.resource_attributes);
+    merged.attributes.remove("include");
+    merged.attributes.remove("unset");
+
+    for key in unset_keys {
+        merged.messages.remove(&key);
+    }
+
+    Ok(merged)
+}
+
+fn include_paths(data: &Data) -> Vec<String> {
+    data.attributes
+        .get("include")
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_str).map(String::from).collect())
+        .unwrap_or_default()
+}
 
-    Ok(json)
+fn unset_keys(data: &Data) -> HashSet<String> {
+    data.attributes
+        .get("unset")
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_str).map(String::from).collect())
+        .unwrap_or_default()
 }
 
 #[cfg(test)]
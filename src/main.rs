@@ -1,16 +1,67 @@
+mod baseline;
+mod discover;
 mod file_opt;
+mod report;
+mod watch;
 
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 use color_eyre::Result;
 
+use discover::resolve_files;
 use file_opt::*;
+use report::Report;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Human-readable text, printed only when there is something to report (inconsistent
+    /// files, or untranslated/empty warnings in template mode).
+    Text,
+    /// Machine-readable JSON report, always printed.
+    Json,
+}
 
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
-    /// List JSON or arb files keys to checked
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// List of JSON/arb files, directories, or glob patterns (e.g. `lib/l10n` or `**/*.arb`) to check
     #[arg(short, long, value_delimiter = ' ', required = true)]
     file: Vec<String>,
+
+    /// Glob patterns to exclude while resolving directory/glob entries in `--file`
+    #[arg(long, value_delimiter = ' ')]
+    ignore: Vec<String>,
+
+    /// Output format for the reconciliation report
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Treat this file (one of the entries resolved from `--file`) as the source of truth;
+    /// every other file is validated against it instead of pairwise-equal
+    #[arg(long)]
+    template: Option<String>,
+
+    /// Keep running and re-check whenever a tracked file changes, instead of exiting after
+    /// the first run
+    #[arg(long)]
+    watch: bool,
+
+    /// Compare the discovered key set against a baseline snapshot written by the `snapshot`
+    /// subcommand, reporting any keys added or removed since it was written
+    #[arg(long)]
+    baseline: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Write a baseline snapshot of the currently discovered key set to a JSON file, for
+    /// later comparison via `--baseline`
+    Snapshot {
+        /// Path to write the baseline snapshot JSON to
+        out: String,
+    },
 }
 
 fn main() -> Result<()> {
@@ -18,24 +69,97 @@ fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
-    if cli.file.len() < 2 {
+    let files = resolve_files(&cli.file, &cli.ignore)?;
+
+    if files.len() < 2 {
         return Err(color_eyre::eyre::eyre!("provide at least two files"));
     }
 
-    check_file_extension(&cli.file)?;
+    check_file_extension(&files)?;
 
-    for file in &cli.file {
+    for file in &files {
         check_files_exist(file)?;
     }
 
-    let mut file_vec: Vec<Data> = vec![];
-    for file in &cli.file {
-        let res = read_json(file)?;
-        file_vec.push(res);
+    if let Some(Command::Snapshot { out }) = &cli.command {
+        let keys = files
+            .iter()
+            .map(read_json_with_includes)
+            .collect::<Result<Vec<Data>>>()?
+            .into_iter()
+            .flat_map(|data| data.messages.into_keys())
+            .collect();
+
+        baseline::write_snapshot(out, keys)?;
+        println!("wrote baseline snapshot to `{out}`");
+        return Ok(());
     }
 
-    check_key_length(&file_vec)?;
-    check_files_equal(file_vec)?;
+    let reference_index = cli
+        .template
+        .as_ref()
+        .map(|template| {
+            files.iter().position(|file| file == template).ok_or_else(|| {
+                color_eyre::eyre::eyre!("template file `{template}` not found among --file entries")
+            })
+        })
+        .transpose()?;
+
+    let run = || -> Result<()> {
+        let mut file_vec: Vec<Data> = vec![];
+        for file in &files {
+            let res = read_json_with_includes(file)?;
+            file_vec.push(res);
+        }
+
+        // A template locale is allowed to have more or fewer keys than its translations, so
+        // the pairwise key-count check only applies when there is no designated reference
+        // locale.
+        if reference_index.is_none() {
+            check_key_length(&file_vec)?;
+        }
+        check_placeholders_equal(&file_vec)?;
+
+        let report = Report::reconcile(&files, &file_vec, reference_index);
+
+        match cli.format {
+            Format::Json => println!("{}", report.to_json()?),
+            Format::Text if !report.is_empty_report() => eprint!("{report}"),
+            Format::Text => {}
+        }
 
-    Ok(())
+        let mut drift = baseline::Drift::default();
+        if let Some(path) = &cli.baseline {
+            let current = file_vec
+                .iter()
+                .flat_map(|data| data.messages.keys().cloned())
+                .collect();
+            drift = baseline::diff_against_snapshot(path, &current)?;
+
+            if !drift.is_empty() {
+                eprintln!("key drift since baseline snapshot `{path}`:");
+                if !drift.added.is_empty() {
+                    eprintln!("  added: {}", drift.added.join(", "));
+                }
+                if !drift.removed.is_empty() {
+                    eprintln!("  removed: {}", drift.removed.join(", "));
+                }
+            }
+        }
+
+        if !report.is_consistent() {
+            return Err(color_eyre::eyre::eyre!("files does not have the same keys"));
+        }
+        if !drift.is_empty() {
+            return Err(color_eyre::eyre::eyre!("keys have drifted since baseline snapshot"));
+        }
+
+        Ok(())
+    };
+
+    if cli.watch {
+        watch::watch(&files, run)
+    } else {
+        run()
+    }
 }
@@ -0,0 +1,290 @@
+use color_eyre::{eyre::Context, Result};
+
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+use crate::file_opt::Data;
+
+/// A single file's key reconciliation result: which message keys it is missing relative
+/// to the baseline (the union of all files', or a reference file's, keys), which message
+/// keys it alone has, and (only in reference-file mode) which of its translations still
+/// need attention.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FileReport {
+    pub missing: Vec<String>,
+    pub extra: Vec<String>,
+    /// Keys whose value is byte-identical to the reference/template value, i.e. likely
+    /// never translated. Only populated in reference-file mode.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub untranslated: Vec<String>,
+    /// Keys whose value is an empty string. Only populated in reference-file mode.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub empty: Vec<String>,
+}
+
+impl FileReport {
+    fn is_consistent(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+
+    fn is_empty_report(&self) -> bool {
+        self.is_consistent() && self.untranslated.is_empty() && self.empty.is_empty()
+    }
+}
+
+/// A full reconciliation report, keyed by file path, computed by comparing every file's
+/// translatable message keys against a baseline key set rather than stopping at the first
+/// discrepancy.
+///
+/// The second field is the sorted union of every key seen across all files, kept only so
+/// `Display` can render a unified-diff-style block in union mode; it is empty in
+/// reference-file mode, where `missing`/`extra` alone don't capture enough to diff against.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Report(pub HashMap<String, FileReport>, #[serde(skip)] BTreeSet<String>);
+
+impl Report {
+    /// Builds a reconciliation report pairing each of `files` with its parsed `data`.
+    ///
+    /// When `reference` is `None`, every file is compared against the union of all files'
+    /// message keys: `missing` lists union keys the file lacks, and `extra` lists keys only
+    /// that single file has. When `reference` is `Some(index)`, every file is instead
+    /// compared directionally against `data[index]`, the designated template: `missing`
+    /// lists template keys the file lacks, `extra` lists keys the file has that the
+    /// template does not (stale or misspelled keys), `untranslated` lists shared keys whose
+    /// value is byte-identical to the template's, and `empty` lists shared keys whose value
+    /// is an empty string.
+    pub fn reconcile(files: &[String], data: &[Data], reference: Option<usize>) -> Report {
+        match reference {
+            Some(reference_index) => Self::reconcile_against_reference(files, data, reference_index),
+            None => Self::reconcile_against_union(files, data),
+        }
+    }
+
+    fn reconcile_against_union(files: &[String], data: &[Data]) -> Report {
+        let mut key_counts: HashMap<&str, usize> = HashMap::new();
+        for d in data {
+            for key in d.messages.keys() {
+                *key_counts.entry(key.as_str()).or_insert(0) += 1;
+            }
+        }
+        let union: BTreeSet<&str> = key_counts.keys().copied().collect();
+
+        let by_file = files
+            .iter()
+            .zip(data)
+            .map(|(file, d)| {
+                let keys: HashSet<&str> = d.messages.keys().map(String::as_str).collect();
+
+                let missing = union
+                    .iter()
+                    .filter(|key| !keys.contains(*key))
+                    .map(|key| key.to_string())
+                    .collect();
+                let extra = keys
+                    .iter()
+                    .filter(|key| key_counts.get(**key) == Some(&1))
+                    .map(|key| key.to_string())
+                    .collect();
+
+                (file.clone(), FileReport { missing, extra, ..FileReport::default() })
+            })
+            .collect();
+
+        Report(by_file, union.into_iter().map(str::to_string).collect())
+    }
+
+    fn reconcile_against_reference(files: &[String], data: &[Data], reference_index: usize) -> Report {
+        let reference_data = &data[reference_index];
+        let reference_keys: HashSet<&str> = reference_data
+            .messages
+            .keys()
+            .map(String::as_str)
+            .collect();
+
+        let by_file = files
+            .iter()
+            .zip(data)
+            .enumerate()
+            .map(|(index, (file, d))| {
+                let keys: HashSet<&str> = d.messages.keys().map(String::as_str).collect();
+
+                let missing = reference_keys
+                    .iter()
+                    .filter(|key| !keys.contains(*key))
+                    .map(|key| key.to_string())
+                    .collect();
+                let extra = keys
+                    .iter()
+                    .filter(|key| !reference_keys.contains(*key))
+                    .map(|key| key.to_string())
+                    .collect();
+
+                let (mut untranslated, mut empty) = (Vec::new(), Vec::new());
+                if index != reference_index {
+                    for key in keys.intersection(&reference_keys) {
+                        let Some(value) = d.messages.get(*key) else {
+                            continue;
+                        };
+
+                        if value.as_str() == Some("") {
+                            empty.push((*key).to_string());
+                        } else if Some(value) == reference_data.messages.get(*key) {
+                            untranslated.push((*key).to_string());
+                        }
+                    }
+                    untranslated.sort();
+                    empty.sort();
+                }
+
+                (
+                    file.clone(),
+                    FileReport {
+                        missing,
+                        extra,
+                        untranslated,
+                        empty,
+                    },
+                )
+            })
+            .collect();
+
+        Report(by_file, BTreeSet::new())
+    }
+
+    /// Returns `true` if every file's key set matches the baseline exactly. Warnings
+    /// (`untranslated`, `empty`) do not affect this.
+    pub fn is_consistent(&self) -> bool {
+        self.0.values().all(FileReport::is_consistent)
+    }
+
+    /// Returns `true` if there is nothing at all to report: every file's key set matches
+    /// the baseline exactly *and* no `untranslated`/`empty` warnings were raised. Unlike
+    /// `is_consistent`, this is what text output should gate on, since `Display` renders
+    /// those warnings too.
+    pub fn is_empty_report(&self) -> bool {
+        self.0.values().all(FileReport::is_empty_report)
+    }
+
+    /// Serializes the report as
+    /// `{ file path: { missing: [...], extra: [...] } }` JSON, with `untranslated` and
+    /// `empty` included only when non-empty (i.e. only in reference-file mode).
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(&self.0).wrap_err("could not serialize report as json")
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut files: Vec<&String> = self.0.keys().collect();
+        files.sort();
+
+        for file in files {
+            let report = &self.0[file];
+            if report.is_empty_report() {
+                continue;
+            }
+
+            // `self.1` (the union baseline) is only populated in union mode; reference mode
+            // has no single baseline to diff against, so it falls back to a plain list.
+            if self.1.is_empty() {
+                writeln!(f, "{file}:")?;
+                if !report.missing.is_empty() {
+                    writeln!(f, "  missing: {}", report.missing.join(", "))?;
+                }
+                if !report.extra.is_empty() {
+                    writeln!(f, "  extra: {}", report.extra.join(", "))?;
+                }
+                if !report.untranslated.is_empty() {
+                    writeln!(f, "  untranslated: {}", report.untranslated.join(", "))?;
+                }
+                if !report.empty.is_empty() {
+                    writeln!(f, "  empty: {}", report.empty.join(", "))?;
+                }
+                continue;
+            }
+
+            writeln!(f, "--- union\n+++ {file}")?;
+            write_unified_diff(f, &self.1, &report.missing, &report.extra)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Number of unchanged context keys kept around each run of changed keys when rendering a
+/// unified-diff-style block, mirroring rustfmt's `DIFF_CONTEXT_SIZE`.
+const DIFF_CONTEXT_SIZE: usize = 3;
+
+enum DiffLine<'a> {
+    Context(&'a str),
+    Missing(&'a str),
+    Extra(&'a str),
+}
+
+/// Writes `keys` (the union baseline, in sorted order) as a unified-diff-style block: a key
+/// the file shares with the baseline is printed as context, a key in `missing` is prefixed
+/// `-`, and a key in `extra` is prefixed `+`, with only `DIFF_CONTEXT_SIZE` lines of context
+/// kept around each run of changes and distant runs separated by a `...` line.
+fn write_unified_diff(
+    f: &mut fmt::Formatter<'_>,
+    keys: &BTreeSet<String>,
+    missing: &[String],
+    extra: &[String],
+) -> fmt::Result {
+    let missing: HashSet<&str> = missing.iter().map(String::as_str).collect();
+    let extra: HashSet<&str> = extra.iter().map(String::as_str).collect();
+
+    let lines: Vec<DiffLine> = keys
+        .iter()
+        .map(|key| {
+            if missing.contains(key.as_str()) {
+                DiffLine::Missing(key)
+            } else if extra.contains(key.as_str()) {
+                DiffLine::Extra(key)
+            } else {
+                DiffLine::Context(key)
+            }
+        })
+        .collect();
+
+    for (index, (start, end)) in hunk_ranges(&lines, DIFF_CONTEXT_SIZE).into_iter().enumerate() {
+        if index > 0 {
+            writeln!(f, "...")?;
+        }
+        for line in &lines[start..end] {
+            match line {
+                DiffLine::Context(key) => writeln!(f, "  {key}")?,
+                DiffLine::Missing(key) => writeln!(f, "- {key}")?,
+                DiffLine::Extra(key) => writeln!(f, "+ {key}")?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Collapses `lines` into `(start, end)` ranges covering every changed line plus up to
+/// `context` unchanged lines on either side, merging ranges that overlap or touch.
+fn hunk_ranges(lines: &[DiffLine], context: usize) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        if matches!(line, DiffLine::Context(_)) {
+            continue;
+        }
+
+        let start = index.saturating_sub(context);
+        let end = (index + context + 1).min(lines.len());
+        match ranges.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end.max(*last_end),
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+#[path = "tests/report_tests.rs"]
+mod report_tests;
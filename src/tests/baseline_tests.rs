@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use crate::baseline::*;
+    use std::collections::BTreeSet;
+    use tempfile::tempdir;
+
+    fn keys(values: &[&str]) -> BTreeSet<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_diff_against_snapshot_detects_added_and_removed_keys() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        let path = path.to_string_lossy().into_owned();
+
+        write_snapshot(&path, keys(&["greeting", "farewell"])).unwrap();
+
+        let current = keys(&["greeting", "welcome"]);
+        let drift = diff_against_snapshot(&path, &current).unwrap();
+
+        assert_eq!(drift.added, vec!["welcome".to_string()]);
+        assert_eq!(drift.removed, vec!["farewell".to_string()]);
+        assert!(!drift.is_empty());
+    }
+
+    #[test]
+    fn test_diff_against_snapshot_empty_when_unchanged() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        let path = path.to_string_lossy().into_owned();
+
+        write_snapshot(&path, keys(&["greeting", "farewell"])).unwrap();
+
+        let current = keys(&["greeting", "farewell"]);
+        let drift = diff_against_snapshot(&path, &current).unwrap();
+
+        assert!(drift.is_empty());
+    }
+
+    #[test]
+    fn test_diff_against_snapshot_missing_file_errors() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nonexistent.json").to_string_lossy().into_owned();
+
+        let result = diff_against_snapshot(&path, &keys(&["greeting"]));
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("could not read baseline snapshot"));
+    }
+}
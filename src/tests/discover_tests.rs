@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use crate::discover::*;
+
+    use std::fs::{self, File};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_resolve_files_explicit_paths() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("en.arb");
+        File::create(&file_path).unwrap();
+
+        let files = resolve_files(&[file_path.to_string_lossy().into_owned()], &[]).unwrap();
+        assert_eq!(files, vec![file_path.to_string_lossy().into_owned()]);
+    }
+
+    #[test]
+    fn test_resolve_files_directory_collects_arb_and_json_only() {
+        let dir = tempdir().unwrap();
+        File::create(dir.path().join("en.arb")).unwrap();
+        File::create(dir.path().join("fr.json")).unwrap();
+        File::create(dir.path().join("README.md")).unwrap();
+
+        let mut files = resolve_files(&[dir.path().to_string_lossy().into_owned()], &[]).unwrap();
+        files.sort();
+
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().any(|f| f.ends_with("en.arb")));
+        assert!(files.iter().any(|f| f.ends_with("fr.json")));
+    }
+
+    #[test]
+    fn test_resolve_files_glob_pattern_is_scoped_to_base_dir() {
+        let dir = tempdir().unwrap();
+        let l10n = dir.path().join("l10n");
+        let vendor = dir.path().join("vendor");
+        fs::create_dir(&l10n).unwrap();
+        fs::create_dir(&vendor).unwrap();
+        File::create(l10n.join("en.arb")).unwrap();
+        File::create(vendor.join("en.arb")).unwrap();
+
+        let pattern = format!("{}/**/*.arb", l10n.to_string_lossy());
+        let files = resolve_files(&[pattern], &[]).unwrap();
+
+        assert_eq!(files, vec![l10n.join("en.arb").to_string_lossy().into_owned()]);
+    }
+
+    #[test]
+    fn test_resolve_files_directory_recurses_into_nested_subdirectories() {
+        let dir = tempdir().unwrap();
+        let nested = dir.path().join("l10n").join("overrides");
+        fs::create_dir_all(&nested).unwrap();
+        File::create(dir.path().join("en.arb")).unwrap();
+        File::create(dir.path().join("l10n").join("fr.arb")).unwrap();
+        File::create(nested.join("de.json")).unwrap();
+        File::create(nested.join("notes.txt")).unwrap();
+
+        let mut files = resolve_files(&[dir.path().to_string_lossy().into_owned()], &[]).unwrap();
+        files.sort();
+
+        assert_eq!(files.len(), 3);
+        assert!(files.iter().any(|f| f.ends_with("en.arb")));
+        assert!(files.iter().any(|f| f.ends_with("fr.arb")));
+        assert!(files.iter().any(|f| f.ends_with("de.json")));
+        assert!(!files.iter().any(|f| f.ends_with("notes.txt")));
+    }
+
+    #[test]
+    fn test_resolve_files_ignore_pattern_prunes_directory() {
+        let dir = tempdir().unwrap();
+        let generated = dir.path().join("generated");
+        fs::create_dir(&generated).unwrap();
+        File::create(dir.path().join("en.arb")).unwrap();
+        File::create(generated.join("en.arb")).unwrap();
+
+        let files = resolve_files(
+            &[dir.path().to_string_lossy().into_owned()],
+            &["generated".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(files, vec![dir.path().join("en.arb").to_string_lossy().into_owned()]);
+    }
+}
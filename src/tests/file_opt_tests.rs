@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::file_opt::*;
+    use std::collections::HashSet;
 
     /// Unit test of `check_file_extension` function.
     #[test]
@@ -66,19 +67,47 @@ mod tests {
 
         let result = read_json(&file_path);
 
-        let mut expected_data = HashMap::new();
-        expected_data.insert("key".to_string(), "value".to_string());
+        let expected_data = Data::from_messages([("key", "value")]);
 
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), expected_data);
     }
 
+    #[test]
+    fn test_read_json_splits_arb_attributes() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("valid.arb");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(
+            file,
+            r#"{{
+                "@@locale": "en",
+                "greeting": "Hello",
+                "@greeting": {{ "description": "A greeting" }}
+            }}"#
+        )
+        .unwrap();
+
+        let result = read_json(&file_path).unwrap();
+
+        assert_eq!(result.messages.get("greeting").unwrap(), "Hello");
+        assert_eq!(result.attributes.get("locale").unwrap(), "en");
+        assert_eq!(
+            result
+                .resource_attributes
+                .get("greeting")
+                .unwrap()
+                .get_str("description"),
+            Some("A greeting")
+        );
+    }
+
     #[test]
     fn test_read_json_invalid() {
         let dir = tempdir().unwrap();
         let file_path = dir.path().join("invalid.json");
         let mut file = File::create(&file_path).unwrap();
-        writeln!(file, r#"{{ "invalid": 123 }}"#).unwrap();
+        writeln!(file, r#"["not", "an", "object"]"#).unwrap();
 
         let result = read_json(&file_path);
 
@@ -107,20 +136,8 @@ mod tests {
     /// Unit test of key-value pair count check.
     #[test]
     fn test_check_key_length_same_count() {
-        let data1: Data = [
-            (String::from("key1"), String::from("value1")),
-            (String::from("key2"), String::from("value2")),
-        ]
-        .iter()
-        .cloned()
-        .collect();
-        let data2: Data = [
-            (String::from("key1"), String::from("value1")),
-            (String::from("key2"), String::from("value2")),
-        ]
-        .iter()
-        .cloned()
-        .collect();
+        let data1 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
+        let data2 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
         let data_slice: &[Data] = &[data1, data2];
 
         let result = check_key_length(data_slice);
@@ -129,17 +146,8 @@ mod tests {
 
     #[test]
     fn test_check_key_length_different_count() {
-        let data1: Data = [
-            (String::from("key1"), String::from("value1")),
-            (String::from("key2"), String::from("value2")),
-        ]
-        .iter()
-        .cloned()
-        .collect();
-        let data2: Data = [(String::from("key1"), String::from("value1"))]
-            .iter()
-            .cloned()
-            .collect();
+        let data1 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
+        let data2 = Data::from_messages([("key1", "value1")]);
         let data_slice: &[Data] = &[data1, data2];
 
         let result = check_key_length(data_slice);
@@ -152,14 +160,8 @@ mod tests {
 
     #[test]
     fn test_check_key_length_empty_data() {
-        let data1: Data = HashMap::new();
-        let data2: Data = [
-            (String::from("key1"), String::from("value1")),
-            (String::from("key2"), String::from("value2")),
-        ]
-        .iter()
-        .cloned()
-        .collect();
+        let data1 = Data::default();
+        let data2 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
         let data_slice: &[Data] = &[data1, data2];
 
         let result = check_key_length(data_slice);
@@ -171,20 +173,8 @@ mod tests {
     /// Unit test of `check_files_equal` function.
     #[test]
     fn test_check_files_equal_same_keys() {
-        let data1: Data = [
-            (String::from("key1"), String::from("value1")),
-            (String::from("key2"), String::from("value2")),
-        ]
-        .iter()
-        .cloned()
-        .collect();
-        let data2: Data = [
-            (String::from("key1"), String::from("value1")),
-            (String::from("key2"), String::from("value2")),
-        ]
-        .iter()
-        .cloned()
-        .collect();
+        let data1 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
+        let data2 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
         let data_vec: Vec<Data> = vec![data1, data2];
 
         let result = check_files_equal(data_vec);
@@ -193,20 +183,8 @@ mod tests {
 
     #[test]
     fn test_check_files_equal_different_keys() {
-        let data1: Data = [
-            (String::from("key1"), String::from("value1")),
-            (String::from("key2"), String::from("value2")),
-        ]
-        .iter()
-        .cloned()
-        .collect();
-        let data2: Data = [
-            (String::from("key4"), String::from("value1")),
-            (String::from("key3"), String::from("value2")),
-        ]
-        .iter()
-        .cloned()
-        .collect();
+        let data1 = Data::from_messages([("key1", "value1"), ("key2", "value2")]);
+        let data2 = Data::from_messages([("key4", "value1"), ("key3", "value2")]);
         let data_vec: Vec<Data> = vec![data1, data2];
 
         let result = check_files_equal(data_vec);
@@ -216,4 +194,153 @@ mod tests {
             .to_string()
             .contains("files does not have the same keys"));
     }
+
+    #[test]
+    fn test_check_files_equal_ignores_attribute_keys() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("with_attrs.arb");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(
+            file,
+            r#"{{ "@@locale": "en", "greeting": "Hello", "@greeting": {{ "description": "hi" }} }}"#
+        )
+        .unwrap();
+        let data1 = read_json(&file_path).unwrap();
+        let data2 = Data::from_messages([("greeting", "Hallo")]);
+
+        let result = check_files_equal(vec![data1, data2]);
+        assert!(result.is_ok());
+    }
+    ///
+
+    /// Unit test of `check_placeholders_equal` function.
+    #[test]
+    fn test_check_placeholders_equal_same_placeholders() {
+        let data1 = Data::from_messages([("greeting", "Hello {name}")]);
+        let data2 = Data::from_messages([("greeting", "Bonjour {name}")]);
+
+        let result = check_placeholders_equal(&[data1, data2]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_check_placeholders_equal_missing_placeholder() {
+        let data1 = Data::from_messages([("greeting", "Hello {name}")]);
+        let data2 = Data::from_messages([("greeting", "Bonjour")]);
+
+        let result = check_placeholders_equal(&[data1, data2]);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("placeholders differ for keys: `greeting`"));
+    }
+
+    #[test]
+    fn test_check_placeholders_equal_plural_and_select() {
+        let data1 = Data::from_messages([(
+            "items",
+            "{count, plural, one{1 item} other{{count} items}}",
+        )]);
+        let data2 = Data::from_messages([(
+            "items",
+            "{count, plural, one{{count} article} other{{count} articles}}",
+        )]);
+
+        let result = check_placeholders_equal(&[data1, data2]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_check_placeholders_equal_plural_keywords_are_not_placeholders() {
+        let mut placeholders = HashSet::new();
+        collect_placeholders(
+            "{count, plural, one{1 item} other{{count} items}}",
+            &mut placeholders,
+        );
+
+        assert_eq!(placeholders, HashSet::from(["count".to_string()]));
+    }
+    ///
+
+    /// Unit test of `read_json_with_includes` function.
+    #[test]
+    fn test_read_json_with_includes_merges_common_keys() {
+        let dir = tempdir().unwrap();
+        let common_path = dir.path().join("common.arb");
+        let mut common = File::create(&common_path).unwrap();
+        writeln!(common, r#"{{ "ok": "OK", "cancel": "Cancel" }}"#).unwrap();
+
+        let en_path = dir.path().join("en.arb");
+        let mut en = File::create(&en_path).unwrap();
+        writeln!(
+            en,
+            r#"{{ "@@include": ["common.arb"], "greeting": "Hello" }}"#
+        )
+        .unwrap();
+
+        let data = read_json_with_includes(&en_path).unwrap();
+
+        assert_eq!(data.messages.get("greeting").unwrap(), "Hello");
+        assert_eq!(data.messages.get("ok").unwrap(), "OK");
+        assert_eq!(data.messages.get("cancel").unwrap(), "Cancel");
+    }
+
+    #[test]
+    fn test_read_json_with_includes_own_keys_take_precedence() {
+        let dir = tempdir().unwrap();
+        let common_path = dir.path().join("common.arb");
+        let mut common = File::create(&common_path).unwrap();
+        writeln!(common, r#"{{ "ok": "OK" }}"#).unwrap();
+
+        let en_path = dir.path().join("en.arb");
+        let mut en = File::create(&en_path).unwrap();
+        writeln!(
+            en,
+            r#"{{ "@@include": ["common.arb"], "ok": "Okay" }}"#
+        )
+        .unwrap();
+
+        let data = read_json_with_includes(&en_path).unwrap();
+        assert_eq!(data.messages.get("ok").unwrap(), "Okay");
+    }
+
+    #[test]
+    fn test_read_json_with_includes_unset_drops_inherited_key() {
+        let dir = tempdir().unwrap();
+        let common_path = dir.path().join("common.arb");
+        let mut common = File::create(&common_path).unwrap();
+        writeln!(common, r#"{{ "ok": "OK", "cancel": "Cancel" }}"#).unwrap();
+
+        let en_path = dir.path().join("en.arb");
+        let mut en = File::create(&en_path).unwrap();
+        writeln!(
+            en,
+            r#"{{ "@@include": ["common.arb"], "@@unset": ["cancel"] }}"#
+        )
+        .unwrap();
+
+        let data = read_json_with_includes(&en_path).unwrap();
+        assert!(data.messages.contains_key("ok"));
+        assert!(!data.messages.contains_key("cancel"));
+    }
+
+    #[test]
+    fn test_read_json_with_includes_detects_cycle() {
+        let dir = tempdir().unwrap();
+        let a_path = dir.path().join("a.arb");
+        let b_path = dir.path().join("b.arb");
+
+        let mut a = File::create(&a_path).unwrap();
+        writeln!(a, r#"{{ "@@include": ["b.arb"] }}"#).unwrap();
+        let mut b = File::create(&b_path).unwrap();
+        writeln!(b, r#"{{ "@@include": ["a.arb"] }}"#).unwrap();
+
+        let result = read_json_with_includes(&a_path);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("include cycle detected"));
+    }
 }
@@ -0,0 +1,217 @@
+#[cfg(test)]
+mod tests {
+    use crate::file_opt::Data;
+    use crate::report::*;
+
+    #[test]
+    fn test_reconcile_against_union_reports_missing_and_extra() {
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello"), ("farewell", "Bye")]),
+            Data::from_messages([("greeting", "Bonjour")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, None);
+
+        assert!(!report.is_consistent());
+        assert_eq!(report.0["en.arb"].missing, Vec::<String>::new());
+        assert_eq!(report.0["en.arb"].extra, vec!["farewell".to_string()]);
+        assert_eq!(report.0["fr.arb"].missing, vec!["farewell".to_string()]);
+        assert_eq!(report.0["fr.arb"].extra, Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_reconcile_against_union_consistent_when_keys_match() {
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello")]),
+            Data::from_messages([("greeting", "Bonjour")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, None);
+        assert!(report.is_consistent());
+    }
+
+    #[test]
+    fn test_reconcile_against_reference_reports_directionally() {
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello"), ("farewell", "Bye")]),
+            Data::from_messages([("greeting", "Bonjour"), ("typo_key", "Oups")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, Some(0));
+
+        assert_eq!(report.0["fr.arb"].missing, vec!["farewell".to_string()]);
+        assert_eq!(report.0["fr.arb"].extra, vec!["typo_key".to_string()]);
+        assert!(report.0["en.arb"].is_consistent());
+    }
+
+    #[test]
+    fn test_reconcile_against_reference_validates_each_translation_independently() {
+        let files = vec!["app_en.arb".to_string(), "app_fr.arb".to_string(), "app_de.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello"), ("farewell", "Bye")]),
+            Data::from_messages([("greeting", "Bonjour"), ("typo_key", "Oups")]),
+            Data::from_messages([("greeting", "Hallo"), ("farewell", "Tschuss")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, Some(0));
+
+        // app_fr.arb is missing the template's "farewell" key and has a stale "typo_key"
+        // that the template doesn't define.
+        assert_eq!(report.0["app_fr.arb"].missing, vec!["farewell".to_string()]);
+        assert_eq!(report.0["app_fr.arb"].extra, vec!["typo_key".to_string()]);
+
+        // app_de.arb has every template key translated, so it reports clean despite
+        // app_fr.arb's drift.
+        assert!(report.0["app_de.arb"].is_consistent());
+    }
+
+    #[test]
+    fn test_report_to_json_round_trips_through_serde() {
+        let files = vec!["en.arb".to_string()];
+        let data = vec![Data::from_messages([("greeting", "Hello")])];
+
+        let report = Report::reconcile(&files, &data, None);
+        let json = report.to_json().unwrap();
+
+        assert!(json.contains("\"en.arb\""));
+        assert!(json.contains("\"missing\""));
+        assert!(json.contains("\"extra\""));
+    }
+
+    #[test]
+    fn test_reconcile_against_reference_detects_untranslated_and_empty() {
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello"), ("farewell", "Bye")]),
+            Data::from_messages([("greeting", "Hello"), ("farewell", "")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, Some(0));
+
+        assert_eq!(report.0["fr.arb"].untranslated, vec!["greeting".to_string()]);
+        assert_eq!(report.0["fr.arb"].empty, vec!["farewell".to_string()]);
+        assert!(report.0["en.arb"].untranslated.is_empty());
+        assert!(report.0["en.arb"].empty.is_empty());
+    }
+
+    #[test]
+    fn test_reconcile_against_reference_untranslated_does_not_affect_is_consistent() {
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello")]),
+            Data::from_messages([("greeting", "Hello")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, Some(0));
+        assert!(report.is_consistent());
+        assert_eq!(report.0["fr.arb"].untranslated, vec!["greeting".to_string()]);
+    }
+
+    #[test]
+    fn test_display_renders_untranslated_warning_despite_is_consistent() {
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello")]),
+            Data::from_messages([("greeting", "Hello")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, Some(0));
+
+        // Key sets match exactly, so `is_consistent` is true, but `fr.arb`'s translation is
+        // byte-identical to the template: text output must still surface that warning.
+        assert!(report.is_consistent());
+        assert!(!report.is_empty_report());
+        let rendered = report.to_string();
+        assert!(rendered.contains("fr.arb:"));
+        assert!(rendered.contains("untranslated: greeting"));
+    }
+
+    #[test]
+    fn test_display_renders_unified_diff_in_union_mode() {
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello"), ("farewell", "Bye")]),
+            Data::from_messages([("greeting", "Bonjour")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, None);
+        let rendered = report.to_string();
+
+        assert!(rendered.contains("--- union\n+++ en.arb"));
+        assert!(rendered.contains("+ farewell"));
+        assert!(rendered.contains("--- union\n+++ fr.arb"));
+        assert!(rendered.contains("- farewell"));
+        assert!(rendered.contains("  greeting"));
+    }
+
+    #[test]
+    fn test_display_union_mode_omits_consistent_files() {
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello")]),
+            Data::from_messages([("greeting", "Bonjour")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, None);
+        assert_eq!(report.to_string(), "");
+    }
+
+    #[test]
+    fn test_display_collapses_distant_context_with_ellipsis() {
+        let shared: Vec<(String, String)> = (0..20)
+            .map(|i| (format!("k{i:02}"), "v".to_string()))
+            .collect();
+        let mut en_messages = shared.clone();
+        en_messages.push(("a_extra".to_string(), "v".to_string()));
+        en_messages.push(("z_extra".to_string(), "v".to_string()));
+        let fr_messages = shared;
+
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages(en_messages.iter().map(|(k, v)| (k.as_str(), v.as_str()))),
+            Data::from_messages(fr_messages.iter().map(|(k, v)| (k.as_str(), v.as_str()))),
+        ];
+
+        let report = Report::reconcile(&files, &data, None);
+        let rendered = report.to_string();
+
+        assert!(rendered.contains("..."));
+        assert!(!rendered.contains("k10"));
+        assert!(rendered.contains("+ a_extra"));
+        assert!(rendered.contains("+ z_extra"));
+    }
+
+    #[test]
+    fn test_display_falls_back_to_plain_list_in_reference_mode() {
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello"), ("farewell", "Bye")]),
+            Data::from_messages([("greeting", "Bonjour"), ("typo_key", "Oups")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, Some(0));
+        let rendered = report.to_string();
+
+        assert!(!rendered.contains("---"));
+        assert!(rendered.contains("missing: farewell"));
+        assert!(rendered.contains("extra: typo_key"));
+    }
+
+    #[test]
+    fn test_report_to_json_omits_untranslated_and_empty_when_absent() {
+        let files = vec!["en.arb".to_string(), "fr.arb".to_string()];
+        let data = vec![
+            Data::from_messages([("greeting", "Hello")]),
+            Data::from_messages([("greeting", "Bonjour")]),
+        ];
+
+        let report = Report::reconcile(&files, &data, None);
+        let json = report.to_json().unwrap();
+
+        assert!(!json.contains("untranslated"));
+        assert!(!json.contains("\"empty\""));
+    }
+}
@@ -0,0 +1,42 @@
+use color_eyre::{eyre::Context, Result};
+
+use notify::{Event, RecursiveMode, Watcher};
+use std::path::Path;
+use std::sync::mpsc::channel;
+use std::time::Duration;
+
+/// How long to wait after a change event before re-running the pipeline, so a burst of
+/// editor saves (e.g. format-on-save touching several locale files at once) triggers a
+/// single run instead of one per file.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches `files` for changes, calling `run` once immediately and again after each
+/// debounced burst of change events, indefinitely.
+///
+/// `run` reporting a failed check (inconsistent files) does not stop the watch: that is
+/// the expected state during active translation work, so the error is printed and the
+/// watch continues. Only a failure to read the next change event itself ends the watch.
+pub fn watch(files: &[String], mut run: impl FnMut() -> Result<()>) -> Result<()> {
+    let (tx, rx) = channel::<notify::Result<Event>>();
+    let mut watcher =
+        notify::recommended_watcher(tx).wrap_err("could not start file watcher")?;
+
+    for file in files {
+        watcher
+            .watch(Path::new(file), RecursiveMode::NonRecursive)
+            .wrap_err_with(|| format!("could not watch `{file}`"))?;
+    }
+
+    loop {
+        if let Err(err) = run() {
+            eprintln!("{err:?}");
+        }
+
+        rx.recv()
+            .wrap_err("file watcher channel closed")?
+            .wrap_err("file watcher reported an error")?;
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+            event.wrap_err("file watcher reported an error")?;
+        }
+    }
+}
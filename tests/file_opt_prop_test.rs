@@ -19,7 +19,7 @@ fn different_size_maps() -> impl Strategy<Value = Vec<file_opt::Data>> {
                 ),
                 1..10,
             )
-            .prop_map(|vec| vec.into_iter().collect())
+            .prop_map(file_opt::Data::from_messages)
         }),
         1..10,
     )
@@ -33,7 +33,8 @@ fn same_size_maps() -> impl Strategy<Value = Vec<file_opt::Data>> {
                 "[A-Za-z0-9_]{1,8}".prop_map(String::from),
                 "[A-Za-z0-9_]{1,8}".prop_map(String::from),
                 len..=len,
-            ),
+            )
+            .prop_map(file_opt::Data::from_messages),
             1..50,
         )
     })
@@ -92,7 +93,7 @@ proptest! {
     #[test]
     fn test_check_files_equal_same_keys(ref keys in prop::collection::hash_set("[a-z]{1,5}", 1..5), ref vals in "[a-z]{1,5}")  {
         let data: Vec<file_opt::Data> = vec![
-            keys.iter().map(|key| (key.clone(), vals.clone())).collect(),
+            file_opt::Data::from_messages(keys.iter().map(|key| (key.clone(), vals.clone()))),
         ];
 
         // All data items have the same keys
@@ -105,8 +106,8 @@ proptest! {
         keys2.push("unique_key".to_string());  // Add a unique key to the second set
 
         let data: Vec<file_opt::Data> = vec![
-            keys1.iter().map(|key| (key.clone(), val.clone())).collect(),
-            keys2.iter().map(|key| (key.clone(), val.clone())).collect()
+            file_opt::Data::from_messages(keys1.iter().map(|key| (key.clone(), val.clone()))),
+            file_opt::Data::from_messages(keys2.iter().map(|key| (key.clone(), val.clone()))),
         ];
 
         // At least one data item has different keys